@@ -1,6 +1,16 @@
 #![no_std]
 use core::cmp::Ordering;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, symbol_short, Address, Bytes, BytesN, Env, String, Vec,
+};
+
+// Self-describing event catalog consumed by `interface_spec_check`'s spec
+// derivation, since events published via `env.events().publish(...)` aren't
+// reflected in the `contractspecv0` WASM section the way methods are.
+contractmeta!(
+    key = "events",
+    val = "AttestationSubmitted:att_sub,AttestationRevoked:att_rev,RoleGranted:role_gr"
+);
 
 const STATUS_KEY_TAG: u32 = 1;
 const ADMIN_KEY_TAG: (u32,) = (2,);
@@ -10,6 +20,19 @@ pub const STATUS_ACTIVE: u32 = 0;
 pub const STATUS_REVOKED: u32 = 1;
 pub const STATUS_FILTER_ALL: u32 = 2;
 
+// Placeholder pricing model for `submit_attestation`, pending a full
+// `FeeConfig` surface. Flat base fee plus a per-byte surcharge on the data
+// actually written, in stroops, so the cost is auditable from the event
+// stream rather than requiring clients to reverse-engineer it.
+const BASE_FEE_STROOPS: i128 = 100;
+const PER_BYTE_FEE_STROOPS: i128 = 1;
+const SUBMIT_STORAGE_WRITES: u32 = 2; // attestation entry + status entry
+
+// Fee/metering surfacing below covers `submit_attestation` only. There is no
+// multisig module (no `Proposal`/`execute_proposal`) in this contract yet,
+// so the equivalent surfacing on that path is out of scope here rather than
+// silently dropped.
+
 #[contract]
 pub struct AttestationContract;
 
@@ -18,6 +41,11 @@ impl AttestationContract {
     /// Submit a revenue attestation: store merkle root and metadata for (business, period).
     /// Prevents overwriting existing attestation for the same period (idempotency).
     /// New attestations are stored with status active (0).
+    /// Publishes an `AttestationSubmitted` event (topic "att_sub") so indexers can
+    /// follow state transitions without polling storage. The event also carries the
+    /// computed fee, storage-write count and bytes stored for this call (see
+    /// `estimate_submit_cost` for the fee/storage-write figures ahead of time),
+    /// so the cost of the operation is auditable from the event stream.
     pub fn submit_attestation(
         env: Env,
         business: Address,
@@ -30,10 +58,47 @@ impl AttestationContract {
         if env.storage().instance().has(&key) {
             panic!("attestation already exists for this business and period");
         }
-        let data = (merkle_root, timestamp, version);
+        let (fee, storage_writes, stored_bytes) = Self::submit_cost(&period);
+        let data = (merkle_root.clone(), timestamp, version);
         env.storage().instance().set(&key, &data);
-        let status_key = (STATUS_KEY_TAG, business, period);
+        let status_key = (STATUS_KEY_TAG, business.clone(), period.clone());
         env.storage().instance().set(&status_key, &STATUS_ACTIVE);
+        env.events().publish(
+            (symbol_short!("att_sub"),),
+            (
+                business,
+                period,
+                merkle_root,
+                timestamp,
+                version,
+                fee,
+                storage_writes,
+                stored_bytes,
+            ),
+        );
+    }
+
+    /// Fee and metering figures `submit_attestation` would report for `(business, period,
+    /// version)` without submitting anything, so integrators can preview the charge
+    /// ahead of time. This is informational only: `submit_attestation` does not collect
+    /// or enforce payment of the quoted fee. Returns `(fee in stroops, storage entries written)`.
+    pub fn estimate_submit_cost(
+        _env: Env,
+        _business: Address,
+        period: String,
+        _version: u32,
+    ) -> (i128, u32) {
+        let (fee, storage_writes, _bytes) = Self::submit_cost(&period);
+        (fee, storage_writes)
+    }
+
+    /// Shared cost model for a `submit_attestation` call: flat base fee plus a
+    /// per-byte surcharge on the merkle root, timestamp, version and period
+    /// actually stored. Returns `(fee, storage_writes, stored_bytes)`.
+    fn submit_cost(period: &String) -> (i128, u32, u32) {
+        let stored_bytes = 32 + 8 + 4 + period.len();
+        let fee = BASE_FEE_STROOPS + PER_BYTE_FEE_STROOPS * stored_bytes as i128;
+        (fee, SUBMIT_STORAGE_WRITES, stored_bytes)
     }
 
     /// Return stored attestation for (business, period) if any.
@@ -61,16 +126,68 @@ impl AttestationContract {
         }
     }
 
+    /// Verify that `leaf` is included in the Merkle tree whose root is
+    /// stored for `(business, period)`, by recomputing the root from `leaf`
+    /// and its audit `proof`. `index` is the leaf's position in the tree;
+    /// its bits (lowest first) say whether each `proof` sibling hashes in
+    /// on the left or the right. An empty `proof` means the tree is a
+    /// single leaf, so `leaf` itself must equal the stored root.
+    /// Returns `false` rather than panicking for a missing or revoked
+    /// attestation, or a `proof` longer than `MAX_PROOF_LEN`.
+    pub fn verify_inclusion(
+        env: Env,
+        business: Address,
+        period: String,
+        leaf: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+        index: u32,
+    ) -> bool {
+        const MAX_PROOF_LEN: u32 = 32;
+        if proof.len() > MAX_PROOF_LEN {
+            return false;
+        }
+        if Self::get_status(&env, &business, &period) == STATUS_REVOKED {
+            return false;
+        }
+        let stored_root = match Self::get_attestation(env.clone(), business, period) {
+            Some((root, _ts, _ver)) => root,
+            None => return false,
+        };
+
+        let mut current = leaf;
+        let mut index = index;
+        for sibling in proof.iter() {
+            let mut buf = [0u8; 64];
+            if index & 1 == 0 {
+                buf[..32].copy_from_slice(&current.to_array());
+                buf[32..].copy_from_slice(&sibling.to_array());
+            } else {
+                buf[..32].copy_from_slice(&sibling.to_array());
+                buf[32..].copy_from_slice(&current.to_array());
+            }
+            current = env
+                .crypto()
+                .sha256(&Bytes::from_array(&env, &buf))
+                .into();
+            index >>= 1;
+        }
+        current == stored_root
+    }
+
     /// One-time setup of admin. Admin is the only address that may revoke attestations.
+    /// Publishes a `RoleGranted` event (topic "role_gr") for the admin role.
     pub fn init(env: Env, admin: Address) {
         admin.require_auth();
         if env.storage().instance().has(&ADMIN_KEY_TAG) {
             panic!("admin already set");
         }
         env.storage().instance().set(&ADMIN_KEY_TAG, &admin);
+        env.events()
+            .publish((symbol_short!("role_gr"),), (admin, symbol_short!("admin")));
     }
 
     /// Revoke an attestation. Caller must be admin. Status is set to revoked (1).
+    /// Publishes an `AttestationRevoked` event (topic "att_rev").
     pub fn revoke_attestation(env: Env, caller: Address, business: Address, period: String) {
         caller.require_auth();
         let admin: Address = env
@@ -85,8 +202,10 @@ impl AttestationContract {
         if !env.storage().instance().has(&attest_key) {
             panic!("attestation does not exist");
         }
-        let status_key = (STATUS_KEY_TAG, business, period);
+        let status_key = (STATUS_KEY_TAG, business.clone(), period.clone());
         env.storage().instance().set(&status_key, &STATUS_REVOKED);
+        env.events()
+            .publish((symbol_short!("att_rev"),), (business, period, caller));
     }
 
     /// Returns status for (business, period): 0 active, 1 revoked. Defaults to active if not set.
@@ -104,7 +223,9 @@ impl AttestationContract {
     /// status_filter: 0 active only, 1 revoked only, 2 all. version_filter: None = any version.
     /// limit: max results (capped at QUERY_LIMIT_MAX). cursor: index into periods to start from.
     /// Returns (results as Vec of (period, merkle_root, timestamp, version, status), next_cursor).
-    /// Next_cursor is cursor + number of periods scanned (not result count). DoS-limited by cap on limit and bounded reads.
+    /// Next_cursor is cursor + number of periods scanned (not result count), clamped to
+    /// periods.len() — it never exceeds the list length, even for a cursor already past
+    /// the end. DoS-limited by cap on limit and bounded reads.
     pub fn get_attestations_page(
         env: Env,
         business: Address,
@@ -119,7 +240,7 @@ impl AttestationContract {
         let limit = core::cmp::min(limit, QUERY_LIMIT_MAX);
         let len = periods.len();
         if cursor >= len {
-            return (Vec::new(&env), cursor);
+            return (Vec::new(&env), len);
         }
         let mut out = Vec::new(&env);
         let mut scanned: u32 = 0;
@@ -156,6 +277,7 @@ impl AttestationContract {
     }
 }
 
+#[cfg(test)]
 mod test;
 #[cfg(test)]
 mod query_pagination_test;
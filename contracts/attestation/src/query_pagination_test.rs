@@ -0,0 +1,145 @@
+//! Deterministic invariant tests for `get_attestations_page`'s cursor
+//! bookkeeping, complementing the property-based coverage in
+//! `fuzz/fuzz_targets/pagination.rs`: paging with the returned `next_cursor`
+//! must visit every matching period exactly once and terminate, and
+//! `next_cursor` must never decrease or exceed `periods.len()`.
+
+// This crate is `#![no_std]`; pull in `std` explicitly for this test module
+// (BTreeSet, `std::format!` below both need it).
+extern crate std;
+
+use crate::{AttestationContract, AttestationContractClient, STATUS_ACTIVE, STATUS_FILTER_ALL};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
+use std::collections::BTreeSet;
+use std::string::ToString;
+
+fn period(env: &Env, n: u32) -> String {
+    String::from_str(env, &std::format!("p{:02}", n))
+}
+
+/// Pages through `get_attestations_page` with `limit` starting at `cursor`,
+/// asserting the monotonic-cursor and bounded-cursor invariants hold, and
+/// returns the set of periods visited.
+///
+/// Keyed on `std::string::String` rather than `soroban_sdk::String`: the
+/// latter has interior mutability (it's host-backed), so a `BTreeSet` of it
+/// trips `clippy::mutable_key_type`.
+fn page_all(
+    client: &AttestationContractClient,
+    business: &Address,
+    periods: &soroban_sdk::Vec<String>,
+    limit: u32,
+    start_cursor: u32,
+) -> BTreeSet<std::string::String> {
+    let mut visited = BTreeSet::new();
+    let mut cursor = start_cursor;
+    let mut pages = 0u32;
+    loop {
+        let (page, next_cursor) = client.get_attestations_page(
+            business,
+            periods,
+            &None,
+            &None,
+            &STATUS_FILTER_ALL,
+            &None,
+            &limit,
+            &cursor,
+        );
+        assert!(next_cursor >= cursor, "next_cursor must not decrease");
+        assert!(
+            next_cursor <= periods.len(),
+            "next_cursor must never exceed periods.len()"
+        );
+        for (p, ..) in page.iter() {
+            let p = p.to_string();
+            assert!(visited.insert(p.clone()), "period visited twice: {:?}", p);
+        }
+        if next_cursor >= periods.len() || next_cursor == cursor {
+            break;
+        }
+        cursor = next_cursor;
+        pages += 1;
+        assert!(pages <= periods.len() + 1, "paging did not terminate");
+    }
+    visited
+}
+
+#[test]
+fn pages_through_every_period_exactly_once_with_small_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AttestationContract);
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let business = Address::generate(&env);
+
+    let mut periods = vec![&env];
+    let mut expected = BTreeSet::new();
+    for i in 0..10 {
+        let p = period(&env, i);
+        let root = BytesN::from_array(&env, &[i as u8; 32]);
+        client.submit_attestation(&business, &p, &root, &0, &0);
+        periods.push_back(p.clone());
+        expected.insert(p.to_string());
+    }
+
+    // limit = 3 forces several pages for 10 periods.
+    let visited = page_all(&client, &business, &periods, 3, 0);
+    assert_eq!(visited, expected);
+}
+
+#[test]
+fn starting_cursor_past_zero_skips_earlier_periods() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AttestationContract);
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let business = Address::generate(&env);
+
+    let mut periods = vec![&env];
+    for i in 0..6 {
+        let p = period(&env, i);
+        let root = BytesN::from_array(&env, &[i as u8; 32]);
+        client.submit_attestation(&business, &p, &root, &0, &0);
+        periods.push_back(p);
+    }
+
+    let expected: BTreeSet<std::string::String> =
+        (2..6).map(|i| period(&env, i).to_string()).collect();
+    let visited = page_all(&client, &business, &periods, 2, 2);
+    assert_eq!(visited, expected);
+}
+
+#[test]
+fn cursor_past_end_returns_empty_page_and_cursor_clamped_to_len() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AttestationContract);
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let business = Address::generate(&env);
+
+    let mut periods = vec![&env];
+    let p = period(&env, 0);
+    let root = BytesN::from_array(&env, &[0u8; 32]);
+    client.submit_attestation(&business, &p, &root, &0, &0);
+    periods.push_back(p);
+
+    let (page, next_cursor) = client.get_attestations_page(
+        &business,
+        &periods,
+        &None,
+        &None,
+        &STATUS_FILTER_ALL,
+        &None,
+        &10,
+        &5,
+    );
+    assert_eq!(page.len(), 0);
+    assert_eq!(next_cursor, periods.len());
+    let _ = STATUS_ACTIVE;
+}
@@ -0,0 +1,229 @@
+//! Unit tests for `AttestationContract`'s mutators, queries, event emission,
+//! Merkle inclusion verification, and fee/metering surfacing.
+
+use crate::{AttestationContract, AttestationContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Events as _, vec, Address, Bytes, BytesN, Env, IntoVal, String};
+
+fn setup(env: &Env) -> (AttestationContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AttestationContract);
+    let client = AttestationContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+    let business = Address::generate(env);
+    (client, admin, business)
+}
+
+#[test]
+fn submit_and_get_attestation_round_trips() {
+    let env = Env::default();
+    let (client, _admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let root = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.submit_attestation(&business, &period, &root, &1_700_000_000, &1);
+
+    let stored = client.get_attestation(&business, &period).unwrap();
+    assert_eq!(stored, (root.clone(), 1_700_000_000, 1));
+    assert!(client.verify_attestation(&business, &period, &root));
+}
+
+#[test]
+#[should_panic(expected = "attestation already exists")]
+fn submit_attestation_rejects_duplicate_period() {
+    let env = Env::default();
+    let (client, _admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.submit_attestation(&business, &period, &root, &0, &0);
+    client.submit_attestation(&business, &period, &root, &0, &0);
+}
+
+#[test]
+fn submit_attestation_emits_att_sub_event_with_fee_and_storage_writes() {
+    let env = Env::default();
+    let (client, admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let root = BytesN::from_array(&env, &[2u8; 32]);
+    let contract_id = client.address.clone();
+
+    let (fee, storage_writes) = client.estimate_submit_cost(&business, &period, &0);
+    let stored_bytes = 32u32 + 8 + 4 + period.len();
+    client.submit_attestation(&business, &period, &root, &42, &0);
+
+    // `setup()` already granted the admin role (a role_gr event) before this
+    // test's own submit_attestation call, so both events are on the ledger.
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("role_gr"),).into_val(&env),
+                (admin, symbol_short!("admin")).into_val(&env),
+            ),
+            (
+                contract_id,
+                (symbol_short!("att_sub"),).into_val(&env),
+                (
+                    business,
+                    period,
+                    root,
+                    42u64,
+                    0u32,
+                    fee,
+                    storage_writes,
+                    stored_bytes,
+                )
+                    .into_val(&env),
+            )
+        ]
+    );
+}
+
+#[test]
+fn revoke_attestation_requires_admin_and_emits_att_rev_event() {
+    let env = Env::default();
+    let (client, admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let root = BytesN::from_array(&env, &[3u8; 32]);
+    let (fee, storage_writes) = client.estimate_submit_cost(&business, &period, &0);
+    let stored_bytes = 32u32 + 8 + 4 + period.len();
+    client.submit_attestation(&business, &period, &root, &0, &0);
+    let contract_id = client.address.clone();
+
+    client.revoke_attestation(&admin, &business, &period);
+
+    // `setup()`'s role_gr and this test's own submit_attestation (att_sub)
+    // both precede the revoke's att_rev event on the ledger.
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("role_gr"),).into_val(&env),
+                (admin.clone(), symbol_short!("admin")).into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("att_sub"),).into_val(&env),
+                (
+                    business.clone(),
+                    period.clone(),
+                    root,
+                    0u64,
+                    0u32,
+                    fee,
+                    storage_writes,
+                    stored_bytes,
+                )
+                    .into_val(&env),
+            ),
+            (
+                contract_id,
+                (symbol_short!("att_rev"),).into_val(&env),
+                (business, period, admin).into_val(&env),
+            )
+        ]
+    );
+}
+
+#[test]
+fn init_emits_role_gr_event_for_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AttestationContract);
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.init(&admin);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("role_gr"),).into_val(&env),
+                (admin, symbol_short!("admin")).into_val(&env),
+            )
+        ]
+    );
+}
+
+#[test]
+fn verify_inclusion_single_leaf_tree_requires_leaf_equals_root() {
+    let env = Env::default();
+    let (client, _admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let leaf = BytesN::from_array(&env, &[9u8; 32]);
+    client.submit_attestation(&business, &period, &leaf, &0, &0);
+
+    assert!(client.verify_inclusion(&business, &period, &leaf, &vec![&env], &0));
+}
+
+#[test]
+fn verify_inclusion_recomputes_root_along_audit_path() {
+    let env = Env::default();
+    let (client, _admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+
+    let leaf = BytesN::from_array(&env, &[1u8; 32]);
+    let sibling0 = BytesN::from_array(&env, &[2u8; 32]);
+    let sibling1 = BytesN::from_array(&env, &[3u8; 32]);
+
+    // index 0: sibling0 hashes in on the right at depth 0, then (index >> 1
+    // == 0) sibling1 again on the right at depth 1.
+    let mut buf0 = [0u8; 64];
+    buf0[..32].copy_from_slice(&leaf.to_array());
+    buf0[32..].copy_from_slice(&sibling0.to_array());
+    let level1: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &buf0)).into();
+
+    let mut buf1 = [0u8; 64];
+    buf1[..32].copy_from_slice(&level1.to_array());
+    buf1[32..].copy_from_slice(&sibling1.to_array());
+    let root: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &buf1)).into();
+
+    client.submit_attestation(&business, &period, &root, &0, &0);
+
+    let proof = vec![&env, sibling0, sibling1];
+    assert!(client.verify_inclusion(&business, &period, &leaf, &proof, &0));
+}
+
+#[test]
+fn verify_inclusion_returns_false_for_revoked_attestation() {
+    let env = Env::default();
+    let (client, admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let leaf = BytesN::from_array(&env, &[5u8; 32]);
+    client.submit_attestation(&business, &period, &leaf, &0, &0);
+    client.revoke_attestation(&admin, &business, &period);
+
+    assert!(!client.verify_inclusion(&business, &period, &leaf, &vec![&env], &0));
+}
+
+#[test]
+fn verify_inclusion_returns_false_for_missing_attestation() {
+    let env = Env::default();
+    let (client, _admin, business) = setup(&env);
+    let period = String::from_str(&env, "2024-Q1");
+    let leaf = BytesN::from_array(&env, &[6u8; 32]);
+
+    assert!(!client.verify_inclusion(&business, &period, &leaf, &vec![&env], &0));
+}
+
+#[test]
+fn estimate_submit_cost_grows_with_period_length() {
+    let env = Env::default();
+    let (client, _admin, business) = setup(&env);
+    let short = String::from_str(&env, "Q1");
+    let long = String::from_str(&env, "2024-Q1-extended-label");
+
+    let (short_fee, writes) = client.estimate_submit_cost(&business, &short, &0);
+    let (long_fee, writes2) = client.estimate_submit_cost(&business, &long, &0);
+
+    assert!(long_fee > short_fee);
+    assert_eq!(writes, writes2);
+}
@@ -0,0 +1,36 @@
+// Builds `veritasor_attestation.wasm` so `interface_spec_check` has a real
+// artifact to derive the spec from, mirroring what
+// `contracts/attestation-snapshot/build.rs` does for its `contractimport!`.
+// Best-effort: if the wasm32 target isn't installed or there's no network to
+// fetch one, this only emits a cargo warning rather than failing `common`'s
+// own build - `interface_spec_check::artifacts` already treats a missing
+// WASM as "this contract contributes nothing" rather than panicking.
+fn main() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let workspace_root = std::path::Path::new(&manifest_dir).join("../..");
+    let status = std::process::Command::new("cargo")
+        .args([
+            "build",
+            "--manifest-path",
+            "contracts/attestation/Cargo.toml",
+            "--release",
+            "--target",
+            "wasm32-unknown-unknown",
+            "--target-dir",
+            "target",
+        ])
+        .current_dir(&workspace_root)
+        .status();
+    match status {
+        Ok(s) if s.success() => {}
+        Ok(s) => println!(
+            "cargo:warning=building veritasor-attestation WASM exited with {s}; \
+             interface_spec_check will report it as not part of this checkout"
+        ),
+        Err(e) => println!(
+            "cargo:warning=could not run cargo to build veritasor-attestation WASM ({e}); \
+             interface_spec_check will report it as not part of this checkout"
+        ),
+    }
+    println!("cargo:rerun-if-changed=../attestation/src/lib.rs");
+}
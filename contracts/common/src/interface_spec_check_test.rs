@@ -3,6 +3,13 @@
 //! These tests verify that the documented interface specification remains
 //! consistent with the actual contract implementations.
 //!
+//! Only `AttestationContract` is part of this checkout; the other contracts
+//! named in the original hand-maintained spec (`AggregatedAttestationsContract`,
+//! `AttestationSnapshotContract`, `AuditLogContract`, `IntegrationRegistryContract`,
+//! `RevenueStreamContract`) live in sibling crates that aren't present here, so
+//! this file checks what `interface_spec_check::artifacts` can actually derive:
+//! zero methods/events/structs for each of them, not guessed counts.
+//!
 //! ## Test Categories
 //!
 //! 1. **Method Count Tests**: Verify expected number of methods per contract
@@ -15,11 +22,61 @@ use soroban_sdk::{Env, String};
 
 // Import the module under test
 use crate::interface_spec_check::{
-    get_event_count, get_expected_events, get_expected_methods, get_expected_structs,
-    get_method_count, get_struct_count, is_event_documented, is_method_documented,
-    is_struct_documented, verify_interface_consistency, VerificationResult,
+    attestation_wasm_available, get_event_count, get_expected_events, get_expected_methods,
+    get_expected_structs, get_method_count, get_struct_count, is_event_documented,
+    is_method_documented, is_struct_documented, verify_interface_consistency, VerificationResult,
 };
 
+const CONTRACTS_NOT_IN_THIS_CHECKOUT: [&str; 5] = [
+    "AggregatedAttestationsContract",
+    "AttestationSnapshotContract",
+    "AuditLogContract",
+    "IntegrationRegistryContract",
+    "RevenueStreamContract",
+];
+
+/// `common`'s `build.rs` builds the attestation WASM best-effort; checkouts
+/// without a `wasm32-unknown-unknown` target (and no network to add one)
+/// won't have it. Tests that assert on its derived counts call this first
+/// and skip rather than fail in that case — `artifacts()` itself already
+/// treats a missing WASM as "contributes nothing", same as any contract not
+/// in this checkout.
+fn skip_if_attestation_wasm_missing() -> bool {
+    if !attestation_wasm_available() {
+        eprintln!(
+            "skipping: veritasor_attestation.wasm not built (no wasm32-unknown-unknown \
+             target available in this environment)"
+        );
+        return true;
+    }
+    false
+}
+
+/// Unlike `skip_if_attestation_wasm_missing`, this one does not skip in CI:
+/// every other test in this file quietly contributes nothing when the WASM
+/// is missing, so a CI environment that's supposed to have built it (and
+/// silently didn't) would otherwise show an all-green suite without
+/// actually having checked any of the derived counts. `CI` is the
+/// convention most CI providers (GitHub Actions among them) set
+/// unconditionally, so its presence is a reasonable proxy for "this build
+/// was expected to produce the artifact."
+#[test]
+fn test_attestation_wasm_available_in_ci() {
+    if std::env::var_os("CI").is_none() {
+        eprintln!(
+            "skipping: not running in CI (no CI env var), wasm32-unknown-unknown may not be \
+             installed locally"
+        );
+        return;
+    }
+    assert!(
+        attestation_wasm_available(),
+        "veritasor_attestation.wasm was not built even though CI is set; every other test in \
+         this file skips rather than fails without it, so the interface-spec derivation would \
+         otherwise go unverified"
+    );
+}
+
 #[test]
 fn test_verification_result_new() {
     let env = Env::default();
@@ -90,57 +147,67 @@ fn test_verification_result_add_error() {
 
 #[test]
 fn test_get_expected_methods_non_empty() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let methods = get_expected_methods(&env);
 
-    // Should have methods from all contracts
+    // AttestationContract alone contributes methods.
     assert!(methods.len() > 0, "Expected methods should not be empty");
 }
 
 #[test]
 fn test_get_expected_events_non_empty() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let events = get_expected_events(&env);
 
-    // Should have events from contracts
     assert!(events.len() > 0, "Expected events should not be empty");
 }
 
 #[test]
-fn test_get_expected_structs_non_empty() {
+fn test_get_expected_structs_is_empty() {
     let env = Env::default();
     let structs = get_expected_structs(&env);
 
-    // Should have structs from all contracts
-    assert!(structs.len() > 0, "Expected structs should not be empty");
+    // AttestationContract stores everything as plain tuples; it defines no
+    // `#[contracttype]` structs, so there is nothing to derive here yet.
+    assert_eq!(
+        structs.len(),
+        0,
+        "AttestationContract defines no structs yet"
+    );
 }
 
 #[test]
 fn test_method_count() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let count = get_method_count(&env);
 
-    // Total methods across all contracts:
-    // AttestationContract: 38
-    // AggregatedAttestationsContract: 5
-    // AttestationSnapshotContract: 10
-    // AuditLogContract: 7
-    // IntegrationRegistryContract: 18
-    // RevenueStreamContract: 5
-    // Total: 83
-    assert_eq!(count, 83, "Total method count should be 83");
+    // AttestationContract: init, submit_attestation, get_attestation,
+    // verify_attestation, revoke_attestation, get_attestations_page,
+    // verify_inclusion, estimate_submit_cost = 8.
+    // The other five contracts aren't part of this checkout (0 each).
+    assert_eq!(count, 8, "Total method count should be 8");
 }
 
 #[test]
 fn test_event_count() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let count = get_event_count(&env);
 
-    // Total events:
-    // AttestationContract: 8
-    // IntegrationRegistryContract: 5
-    // Total: 13
-    assert_eq!(count, 13, "Total event count should be 13");
+    // AttestationContract: AttestationSubmitted, AttestationRevoked,
+    // RoleGranted = 3.
+    assert_eq!(count, 3, "Total event count should be 3");
 }
 
 #[test]
@@ -148,19 +215,14 @@ fn test_struct_count() {
     let env = Env::default();
     let count = get_struct_count(&env);
 
-    // Total structs:
-    // AttestationContract: 10
-    // AggregatedAttestationsContract: 1
-    // AttestationSnapshotContract: 1
-    // AuditLogContract: 1
-    // IntegrationRegistryContract: 3
-    // RevenueStreamContract: 1
-    // Total: 17
-    assert_eq!(count, 17, "Total struct count should be 17");
+    assert_eq!(count, 0, "Total struct count should be 0");
 }
 
 #[test]
 fn test_attestation_contract_method_count() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let methods = get_expected_methods(&env);
 
@@ -171,112 +233,52 @@ fn test_attestation_contract_method_count() {
 
     assert_eq!(
         attestation_methods.len(),
-        38,
-        "AttestationContract should have 38 methods"
-    );
-}
-
-#[test]
-fn test_aggregated_attestations_contract_method_count() {
-    let env = Env::default();
-    let methods = get_expected_methods(&env);
-
-    let aggregated_methods: Vec<_> = methods
-        .iter()
-        .filter(|m| m.contract == String::from_str(&env, "AggregatedAttestationsContract"))
-        .collect();
-
-    assert_eq!(
-        aggregated_methods.len(),
-        5,
-        "AggregatedAttestationsContract should have 5 methods"
+        8,
+        "AttestationContract should have 8 methods"
     );
 }
 
 #[test]
-fn test_snapshot_contract_method_count() {
+fn test_contracts_outside_checkout_contribute_no_methods() {
     let env = Env::default();
     let methods = get_expected_methods(&env);
 
-    let snapshot_methods: Vec<_> = methods
-        .iter()
-        .filter(|m| m.contract == String::from_str(&env, "AttestationSnapshotContract"))
-        .collect();
-
-    assert_eq!(
-        snapshot_methods.len(),
-        10,
-        "AttestationSnapshotContract should have 10 methods"
-    );
-}
-
-#[test]
-fn test_audit_log_contract_method_count() {
-    let env = Env::default();
-    let methods = get_expected_methods(&env);
-
-    let audit_methods: Vec<_> = methods
-        .iter()
-        .filter(|m| m.contract == String::from_str(&env, "AuditLogContract"))
-        .collect();
-
-    assert_eq!(
-        audit_methods.len(),
-        7,
-        "AuditLogContract should have 7 methods"
-    );
-}
-
-#[test]
-fn test_integration_registry_contract_method_count() {
-    let env = Env::default();
-    let methods = get_expected_methods(&env);
-
-    let registry_methods: Vec<_> = methods
-        .iter()
-        .filter(|m| m.contract == String::from_str(&env, "IntegrationRegistryContract"))
-        .collect();
-
-    assert_eq!(
-        registry_methods.len(),
-        18,
-        "IntegrationRegistryContract should have 18 methods"
-    );
-}
-
-#[test]
-fn test_revenue_stream_contract_method_count() {
-    let env = Env::default();
-    let methods = get_expected_methods(&env);
-
-    let stream_methods: Vec<_> = methods
-        .iter()
-        .filter(|m| m.contract == String::from_str(&env, "RevenueStreamContract"))
-        .collect();
-
-    assert_eq!(
-        stream_methods.len(),
-        5,
-        "RevenueStreamContract should have 5 methods"
-    );
+    for contract in CONTRACTS_NOT_IN_THIS_CHECKOUT.iter() {
+        let count = methods
+            .iter()
+            .filter(|m| m.contract == String::from_str(&env, contract))
+            .count();
+        assert_eq!(
+            count, 0,
+            "{} isn't part of this checkout and should contribute no methods",
+            contract
+        );
+    }
 }
 
 #[test]
 fn test_is_method_documented() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
 
     // Test documented methods
     assert!(
-        is_method_documented(&env, "AttestationContract", "initialize"),
-        "initialize should be documented for AttestationContract"
+        is_method_documented(&env, "AttestationContract", "init"),
+        "init should be documented for AttestationContract"
     );
     assert!(
         is_method_documented(&env, "AttestationContract", "submit_attestation"),
         "submit_attestation should be documented"
     );
     assert!(
-        is_method_documented(&env, "IntegrationRegistryContract", "register_provider"),
-        "register_provider should be documented"
+        is_method_documented(&env, "AttestationContract", "verify_inclusion"),
+        "verify_inclusion should be documented"
+    );
+    assert!(
+        is_method_documented(&env, "AttestationContract", "estimate_submit_cost"),
+        "estimate_submit_cost should be documented"
     );
 
     // Test undocumented methods
@@ -285,13 +287,16 @@ fn test_is_method_documented() {
         "nonexistent_method should not be documented"
     );
     assert!(
-        !is_method_documented(&env, "NonexistentContract", "initialize"),
+        !is_method_documented(&env, "NonexistentContract", "init"),
         "NonexistentContract should not have documented methods"
     );
 }
 
 #[test]
 fn test_is_event_documented() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
 
     // Test documented events
@@ -303,37 +308,27 @@ fn test_is_event_documented() {
         is_event_documented(&env, "AttestationContract", "RoleGranted"),
         "RoleGranted should be documented"
     );
-    assert!(
-        is_event_documented(&env, "IntegrationRegistryContract", "ProviderRegistered"),
-        "ProviderRegistered should be documented"
-    );
 
     // Test undocumented events
     assert!(
         !is_event_documented(&env, "AttestationContract", "NonexistentEvent"),
         "NonexistentEvent should not be documented"
     );
+    assert!(
+        !is_event_documented(&env, "IntegrationRegistryContract", "ProviderRegistered"),
+        "IntegrationRegistryContract isn't part of this checkout"
+    );
 }
 
 #[test]
 fn test_is_struct_documented() {
     let env = Env::default();
 
-    // Test documented structs
-    assert!(
-        is_struct_documented(&env, "AttestationContract", "FeeConfig"),
-        "FeeConfig should be documented"
-    );
-    assert!(
-        is_struct_documented(&env, "AttestationContract", "Proposal"),
-        "Proposal should be documented"
-    );
+    // AttestationContract defines no structs, so nothing is struct-documented yet.
     assert!(
-        is_struct_documented(&env, "IntegrationRegistryContract", "Provider"),
-        "Provider should be documented"
+        !is_struct_documented(&env, "AttestationContract", "FeeConfig"),
+        "AttestationContract doesn't define a FeeConfig struct in this checkout"
     );
-
-    // Test undocumented structs
     assert!(
         !is_struct_documented(&env, "AttestationContract", "NonexistentStruct"),
         "NonexistentStruct should not be documented"
@@ -342,10 +337,15 @@ fn test_is_struct_documented() {
 
 #[test]
 fn test_verify_interface_consistency() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let result = verify_interface_consistency(&env);
 
-    // The verification should pass with the current expected counts
+    // Every derived method is documented (via its `///` doc comment) and
+    // every derived event resolves a topic, so this should pass against the
+    // real, current AttestationContract.
     assert!(
         result.passed,
         "Interface consistency verification should pass"
@@ -353,73 +353,52 @@ fn test_verify_interface_consistency() {
 }
 
 #[test]
-fn test_all_contracts_have_initialize() {
+fn test_attestation_contract_has_init() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let methods = get_expected_methods(&env);
 
-    let contracts = [
-        "AttestationContract",
-        "AggregatedAttestationsContract",
-        "AttestationSnapshotContract",
-        "AuditLogContract",
-        "IntegrationRegistryContract",
-        "RevenueStreamContract",
-    ];
-
-    for contract in contracts.iter() {
-        let has_initialize = methods.iter().any(|m| {
-            m.contract == String::from_str(&env, contract)
-                && m.name == String::from_str(&env, "initialize")
-        });
-        assert!(
-            has_initialize,
-            "{} should have an initialize method",
-            contract
-        );
-    }
+    // AttestationContract's one-time setup method is named `init`, not
+    // `initialize` as the original hand-maintained spec assumed.
+    let has_init = methods.iter().any(|m| {
+        m.contract == String::from_str(&env, "AttestationContract")
+            && m.name == String::from_str(&env, "init")
+    });
+    assert!(has_init, "AttestationContract should have an init method");
 }
 
 #[test]
-fn test_all_contracts_have_get_admin() {
+fn test_attestation_contract_has_no_get_admin() {
     let env = Env::default();
     let methods = get_expected_methods(&env);
 
-    let contracts = [
-        "AttestationContract",
-        "AggregatedAttestationsContract",
-        "AttestationSnapshotContract",
-        "AuditLogContract",
-        "IntegrationRegistryContract",
-        "RevenueStreamContract",
-    ];
-
-    for contract in contracts.iter() {
-        let has_get_admin = methods.iter().any(|m| {
-            m.contract == String::from_str(&env, contract)
-                && m.name == String::from_str(&env, "get_admin")
-        });
-        assert!(
-            has_get_admin,
-            "{} should have a get_admin method",
-            contract
-        );
-    }
+    // AttestationContract stores its admin but exposes no accessor yet;
+    // asserting this explicitly (instead of silently dropping the check)
+    // documents the gap until a `get_admin` method is added.
+    let has_get_admin = methods.iter().any(|m| {
+        m.contract == String::from_str(&env, "AttestationContract")
+            && m.name == String::from_str(&env, "get_admin")
+    });
+    assert!(
+        !has_get_admin,
+        "AttestationContract has no get_admin accessor in this checkout"
+    );
 }
 
 #[test]
 fn test_attestation_events_have_correct_topics() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
     let events = get_expected_events(&env);
 
     let expected_topics = [
         ("AttestationSubmitted", "att_sub"),
         ("AttestationRevoked", "att_rev"),
-        ("AttestationMigrated", "att_mig"),
         ("RoleGranted", "role_gr"),
-        ("RoleRevoked", "role_rv"),
-        ("ContractPaused", "paused"),
-        ("ContractUnpaused", "unpaus"),
-        ("FeeConfigChanged", "fee_cfg"),
     ];
 
     for (name, expected_topic) in expected_topics.iter() {
@@ -443,10 +422,12 @@ fn test_attestation_events_have_correct_topics() {
 }
 
 #[test]
-fn test_provider_events_have_correct_topics() {
+fn test_provider_events_not_present_in_this_checkout() {
     let env = Env::default();
     let events = get_expected_events(&env);
 
+    // IntegrationRegistryContract's provider events aren't derivable here:
+    // that contract isn't part of this checkout.
     let expected_topics = [
         ("ProviderRegistered", "prv_reg"),
         ("ProviderEnabled", "prv_ena"),
@@ -455,74 +436,37 @@ fn test_provider_events_have_correct_topics() {
         ("ProviderUpdated", "prv_upd"),
     ];
 
-    for (name, expected_topic) in expected_topics.iter() {
+    for (name, _expected_topic) in expected_topics.iter() {
         let event = events.iter().find(|e| {
             e.name == String::from_str(&env, name)
                 && e.contract == String::from_str(&env, "IntegrationRegistryContract")
         });
         assert!(
-            event.is_some(),
-            "Event {} should exist for IntegrationRegistryContract",
+            event.is_none(),
+            "IntegrationRegistryContract isn't part of this checkout, so {} shouldn't resolve",
             name
         );
-        assert_eq!(
-            event.unwrap().topic,
-            String::from_str(&env, expected_topic),
-            "Event {} should have topic {}",
-            name,
-            expected_topic
-        );
     }
 }
 
-/// Test that will fail if a new method is added to a contract but not documented.
-/// This test should be updated when new methods are added.
+/// Test that will fail if a new method is added to `AttestationContract` but
+/// not documented. This list should be updated when methods are added/removed.
 #[test]
 fn test_method_documentation_completeness() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     let env = Env::default();
 
-    // List of all known public methods that must be documented
-    // This list should be updated when methods are added/removed
     let required_methods = [
-        // AttestationContract
-        ("AttestationContract", "initialize"),
-        ("AttestationContract", "initialize_multisig"),
-        ("AttestationContract", "configure_fees"),
-        ("AttestationContract", "set_tier_discount"),
-        ("AttestationContract", "set_business_tier"),
-        ("AttestationContract", "set_volume_brackets"),
-        ("AttestationContract", "set_fee_enabled"),
-        ("AttestationContract", "grant_role"),
-        ("AttestationContract", "revoke_role"),
-        ("AttestationContract", "has_role"),
-        ("AttestationContract", "get_roles"),
-        ("AttestationContract", "get_role_holders"),
-        ("AttestationContract", "pause"),
-        ("AttestationContract", "unpause"),
-        ("AttestationContract", "is_paused"),
+        ("AttestationContract", "init"),
         ("AttestationContract", "submit_attestation"),
-        ("AttestationContract", "submit_attestation_with_metadata"),
-        ("AttestationContract", "revoke_attestation"),
-        ("AttestationContract", "migrate_attestation"),
-        ("AttestationContract", "is_revoked"),
         ("AttestationContract", "get_attestation"),
-        ("AttestationContract", "get_attestation_metadata"),
         ("AttestationContract", "verify_attestation"),
-        ("AttestationContract", "create_proposal"),
-        ("AttestationContract", "approve_proposal"),
-        ("AttestationContract", "reject_proposal"),
-        ("AttestationContract", "execute_proposal"),
-        ("AttestationContract", "get_proposal"),
-        ("AttestationContract", "get_approval_count"),
-        ("AttestationContract", "is_proposal_approved"),
-        ("AttestationContract", "get_multisig_owners"),
-        ("AttestationContract", "get_multisig_threshold"),
-        ("AttestationContract", "is_multisig_owner"),
-        ("AttestationContract", "get_fee_config"),
-        ("AttestationContract", "get_fee_quote"),
-        ("AttestationContract", "get_business_tier"),
-        ("AttestationContract", "get_business_count"),
-        ("AttestationContract", "get_admin"),
+        ("AttestationContract", "revoke_attestation"),
+        ("AttestationContract", "get_attestations_page"),
+        ("AttestationContract", "verify_inclusion"),
+        ("AttestationContract", "estimate_submit_cost"),
     ];
 
     for (contract, method) in required_methods.iter() {
@@ -538,6 +482,9 @@ fn test_method_documentation_completeness() {
 /// Test that verifies the spec document exists and is accessible.
 #[test]
 fn test_spec_document_exists() {
+    if skip_if_attestation_wasm_missing() {
+        return;
+    }
     // This test verifies that the spec document was created
     // In a real implementation, this could check file existence
     // For now, we verify through the method counts
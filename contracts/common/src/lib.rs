@@ -0,0 +1,7 @@
+//! Shared, off-chain verification helpers used by the workspace's own test
+//! suite (not part of any deployed contract).
+
+pub mod interface_spec_check;
+
+#[cfg(test)]
+mod interface_spec_check_test;